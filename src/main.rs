@@ -1,57 +1,300 @@
 use ggez::{
     event::{self, EventHandler},
-    graphics::{self, Color, DrawMode, DrawParam, Mesh, Rect},
+    graphics::{self, Color, DrawMode, DrawParam, Image, InstanceArray, Mesh, Rect, Text},
+    input::keyboard::{KeyCode, KeyInput},
     input::mouse,
     Context, ContextBuilder, GameResult,
 };
 
 use std::time::{Duration, Instant};
 
-// Constants for grid and screen dimensions
+// Default grid and screen dimensions, used when the matching command-line
+// flag isn't given. The actual board size is runtime-configurable (see
+// `Config`), so these are no longer assumed by the board/index math.
 const GRID_WIDTH: u32 = 100;
 const GRID_HEIGHT: u32 = 100;
 const GRID_CELL_SIZE: i32 = 8;
-const SCREEN_SIZE: (f32, f32) = (
-    GRID_WIDTH as f32 * GRID_CELL_SIZE as f32,
-    GRID_HEIGHT as f32 * GRID_CELL_SIZE as f32,
-);
+// A strip reserved across the top of the window for the parameters
+// toolbar; the board itself is drawn (and clicked) offset below it.
+const TOOLBAR_HEIGHT: f32 = 36.0;
 const TARGET_FPS: f64 = 90.0;
 
+// Upper bound on ticks drained from the accumulator in a single `update`
+// call. Without this, a long stall (e.g. the window being dragged) would
+// otherwise force the board through hundreds of catch-up generations in
+// one frame -- the classic "spiral of death".
+const MAX_TICKS_PER_UPDATE: u32 = 8;
+
+// Bounds on the tick period so the speed keys can't drive the simulation
+// into a busy-spin or a near-standstill.
+const MIN_DT: Duration = Duration::from_millis(1);
+const MAX_DT: Duration = Duration::from_secs(2);
+
+// Rulestring presets the toolbar's rule button cycles through, as
+// (display name, rulestring) pairs.
+const RULE_PRESETS: [(&str, &str); 4] = [
+    ("Conway", "B3/S23"),
+    ("HighLife", "B36/S23"),
+    ("Day & Night", "B3678/S34678"),
+    ("Seeds", "B2/S"),
+];
+
+// One widget slot in the toolbar strip, in toolbar-local (unscrolled) x.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ToolbarWidget {
+    PauseButton,
+    RandomizeButton,
+    ClearButton,
+    RuleButton,
+    DensitySlider,
+}
+
+impl ToolbarWidget {
+    const BUTTON_WIDTH: f32 = 90.0;
+    const SLIDER_WIDTH: f32 = 150.0;
+    // Combined width of every slot at its natural (unscaled) size.
+    const NATURAL_WIDTH: f32 = Self::BUTTON_WIDTH * 4.0 + Self::SLIDER_WIDTH;
+
+    // Left/right edges of every widget's slot, laid out left to right and
+    // scaled so the slots always span exactly `total_width`. Without this,
+    // a CLI-configured board narrower than `NATURAL_WIDTH` would push the
+    // later widgets off the edge of the window and out of reach.
+    fn layout(total_width: f32) -> [(ToolbarWidget, f32, f32); 5] {
+        let scale = total_width / Self::NATURAL_WIDTH;
+        let mut left = 0.0;
+        let mut slot = |widget, width: f32| {
+            let span = (widget, left, left + width * scale);
+            left += width * scale;
+            span
+        };
+
+        [
+            slot(ToolbarWidget::PauseButton, Self::BUTTON_WIDTH),
+            slot(ToolbarWidget::RandomizeButton, Self::BUTTON_WIDTH),
+            slot(ToolbarWidget::ClearButton, Self::BUTTON_WIDTH),
+            slot(ToolbarWidget::RuleButton, Self::BUTTON_WIDTH),
+            slot(ToolbarWidget::DensitySlider, Self::SLIDER_WIDTH),
+        ]
+    }
+
+    // Which widget (if any) occupies screen x coordinate `x`, given the
+    // toolbar's current `total_width`.
+    fn at(x: f32, total_width: f32) -> Option<ToolbarWidget> {
+        Self::layout(total_width)
+            .into_iter()
+            .find(|&(_, left, right)| x >= left && x < right)
+            .map(|(widget, _, _)| widget)
+    }
+}
+
 // Utility functions
 
 // Moved this here as it was used in a few places.
-// Just calculates the x and y coordinates from the given index.
-fn get_coordinates(i: i32) -> (i32, i32) {
-    let x: i32 = i % GRID_WIDTH as i32;
-    let y: i32 = i / GRID_WIDTH as i32;
+// Just calculates the x and y coordinates from the given index, for a
+// board of the given `width`.
+fn get_coordinates(i: i32, width: u32) -> (i32, i32) {
+    let width = width as i32;
+    let x: i32 = i % width;
+    let y: i32 = i / width;
     (x, y)
 }
 
+// A small 64-bit linear congruential PRNG step. Good enough for scattering cells
+// across the board without pulling in a dependency for it.
+fn next_rng_state(state: u64) -> u64 {
+    state
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407)
+}
+
+// Maps a PRNG state to a float in 0.0..1.0.
+fn rng_unit_interval(state: u64) -> f32 {
+    (state >> 40) as f32 / (1u64 << 24) as f32
+}
+
+// A seed derived from the current time, used when the user doesn't pass
+// `--seed` explicitly.
+fn time_based_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64
+        | 1
+}
+
+// Command-line configuration for grid size, cell size, fill density, RNG
+// seed, rule, and boundary mode, so experiments don't require editing
+// constants and recompiling. Unset or unparsable flags fall back to
+// these defaults.
+struct Config {
+    grid_width: u32,
+    grid_height: u32,
+    cell_size: i32,
+    density: f32,
+    seed: u64,
+    rule: Rule,
+    boundary: BoundaryMode,
+}
+
+impl Config {
+    // Parses `--width`, `--height`, `--cell-size`, `--density`, `--seed`,
+    // `--rule`, and `--boundary` flags (each taking a value) out of an
+    // argument iterator. Arguments are read in flag/value pairs, so an
+    // unrecognized flag is reported and skipped together with the argument
+    // that follows it, rather than treated as a hard error.
+    fn from_args(args: impl Iterator<Item = String>) -> Config {
+        let mut config = Config {
+            grid_width: GRID_WIDTH,
+            grid_height: GRID_HEIGHT,
+            cell_size: GRID_CELL_SIZE,
+            density: 0.33,
+            seed: time_based_seed(),
+            rule: Rule::default(),
+            boundary: BoundaryMode::default(),
+        };
+
+        let mut args = args;
+        while let Some(flag) = args.next() {
+            let value = args.next();
+            match (flag.as_str(), value) {
+                ("--width", Some(v)) => config.grid_width = v.parse().unwrap_or(config.grid_width),
+                ("--height", Some(v)) => {
+                    config.grid_height = v.parse().unwrap_or(config.grid_height)
+                }
+                ("--cell-size", Some(v)) => {
+                    config.cell_size = v.parse().unwrap_or(config.cell_size)
+                }
+                ("--density", Some(v)) => {
+                    config.density = v
+                        .parse()
+                        .map(|density: f32| density.clamp(0.0, 1.0))
+                        .unwrap_or(config.density)
+                }
+                ("--seed", Some(v)) => config.seed = v.parse().unwrap_or(config.seed),
+                ("--rule", Some(v)) => {
+                    config.rule = Rule::parse(&v).unwrap_or_else(|| {
+                        eprintln!("Ignoring invalid rulestring {v:?}, using B3/S23");
+                        Rule::default()
+                    })
+                }
+                ("--boundary", Some(v)) => {
+                    config.boundary = match v.to_lowercase().as_str() {
+                        "bounded" => BoundaryMode::Bounded,
+                        "toroidal" | "wrap" => BoundaryMode::Toroidal,
+                        _ => {
+                            eprintln!("Ignoring invalid boundary mode {v:?}, using bounded");
+                            BoundaryMode::Bounded
+                        }
+                    }
+                }
+                (flag, _) => eprintln!("Ignoring unrecognized argument: {flag}"),
+            }
+        }
+
+        config
+    }
+}
+
+// A Life-like rule: a dead cell with `birth[n]` set becomes alive, and a
+// live cell with `survive[n]` set stays alive, where `n` (0..=8) is its
+// count of live neighbors. Conway's B3/S23 is the special case where only
+// `birth[3]` and `survive[2]`/`survive[3]` are set.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Rule {
+    birth: [bool; 9],
+    survive: [bool; 9],
+}
+
+impl Rule {
+    // Parses a "B3/S23"-style rulestring: a `B` section listing birth
+    // neighbor counts, a `/`, then an `S` section listing survival
+    // neighbor counts, each made up of the digits 0-8. Returns `None` for
+    // anything else (missing section, bad prefix, out-of-range digit) so
+    // callers can fall back to Conway's rule.
+    fn parse(rulestring: &str) -> Option<Rule> {
+        let (b_part, s_part) = rulestring.split_once('/')?;
+        let b_digits = b_part
+            .strip_prefix('B')
+            .or_else(|| b_part.strip_prefix('b'))?;
+        let s_digits = s_part
+            .strip_prefix('S')
+            .or_else(|| s_part.strip_prefix('s'))?;
+
+        let mut rule = Rule {
+            birth: [false; 9],
+            survive: [false; 9],
+        };
+
+        for c in b_digits.chars() {
+            rule.birth[digit_0_to_8(c)?] = true;
+        }
+        for c in s_digits.chars() {
+            rule.survive[digit_0_to_8(c)?] = true;
+        }
+
+        Some(rule)
+    }
+}
+
+impl Default for Rule {
+    // Standard Conway life: B3/S23.
+    fn default() -> Rule {
+        Rule::parse("B3/S23").expect("B3/S23 is a valid rulestring")
+    }
+}
+
+// Parses a single char as a neighbor count in 0..=8, the only counts a
+// cell can have.
+fn digit_0_to_8(c: char) -> Option<usize> {
+    match c.to_digit(10)? {
+        n @ 0..=8 => Some(n as usize),
+        _ => None,
+    }
+}
+
+// How `count_alive_neighbors` treats coordinates that fall outside the
+// board. `Bounded` treats the edges as permanently dead, like looking
+// past the edge of a sheet of paper; `Toroidal` wraps them around, so the
+// top edge neighbors the bottom and the left edge neighbors the right.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum BoundaryMode {
+    #[default]
+    Bounded,
+    Toroidal,
+}
+
 struct Board {
     cells: Vec<u8>,
     width: u32,
     height: u32,
+    boundary: BoundaryMode,
 }
 
 impl Board {
     // Create a new board with the given dimensions
-    fn new(width: u32, height: u32) -> Board {
+    fn new(width: u32, height: u32, boundary: BoundaryMode) -> Board {
         let cells = vec![0; (width * height) as usize];
         Board {
             cells,
             width,
             height,
+            boundary,
         }
     }
 
-    // Randomize the board's cells
-    fn randomize(&mut self) {
-        for i in 0..self.cells.len() {
-            if i % 3 == 0 {
-                self.cells[i] = 1;
+    // Randomize the board's cells, filling roughly `density` (0.0..=1.0)
+    // of them. Takes an explicit `seed` rather than sourcing randomness
+    // itself so runs can be reproduced exactly by passing the same seed.
+    fn randomize(&mut self, density: f32, seed: u64) {
+        let mut rng_state = seed | 1;
+
+        for cell in self.cells.iter_mut() {
+            rng_state = next_rng_state(rng_state);
+            *cell = if rng_unit_interval(rng_state) < density {
+                1
             } else {
-                self.cells[i] = 0;
-            }
+                0
+            };
         }
     }
 
@@ -72,15 +315,15 @@ impl Board {
         }
     }
 
-    // Update the board based on the rules of the game.
-    fn update(&mut self, future_board: &mut Board) {
+    // Update the board based on the given rule.
+    fn update(&mut self, future_board: &mut Board, rule: &Rule) {
         for i in 0..self.cells.len() {
-            let cell = &self.cells[i];
-            let alive_neighbors = &self.count_alive_neighbors(i as i32);
+            let cell = self.cells[i];
+            let alive_neighbors = self.count_alive_neighbors(i as i32) as usize;
 
-            future_board.cells[i] = match (cell, alive_neighbors) {
-                (1, 2) | (1, 3) => 1,
-                (0, 3) => 1,
+            future_board.cells[i] = match cell {
+                1 if rule.survive[alive_neighbors] => 1,
+                0 if rule.birth[alive_neighbors] => 1,
                 _ => 0,
             }
         }
@@ -88,7 +331,7 @@ impl Board {
 
     // Count the number of alive neighbors for a cell
     fn count_alive_neighbors(&self, i: i32) -> u8 {
-        let (x, y) = get_coordinates(i);
+        let (x, y) = get_coordinates(i, self.width);
         let mut count = 0;
 
         // precalculating the coordinates of the neighbors rather than using a loop.
@@ -105,8 +348,33 @@ impl Board {
         ];
 
         for (nx, ny) in neighbor_coordinates {
-            if let Some(&cell) = self.get_cell((x + nx) as u32, (y + ny) as u32) {
-                count += cell;
+            let neighbor_x = x + nx;
+            let neighbor_y = y + ny;
+
+            // Casting a negative offset straight to u32 would wrap it into
+            // a huge value that happens to fail the bounds check below, so
+            // bounded-mode coordinates must be checked in signed space
+            // first. Toroidal mode instead reduces them modulo the board
+            // size (via `rem_euclid`, so negatives wrap the right way)
+            // before the cast, which is always in range.
+            let wrapped = match self.boundary {
+                BoundaryMode::Bounded => {
+                    if neighbor_x < 0 || neighbor_y < 0 {
+                        None
+                    } else {
+                        Some((neighbor_x as u32, neighbor_y as u32))
+                    }
+                }
+                BoundaryMode::Toroidal => Some((
+                    neighbor_x.rem_euclid(self.width as i32) as u32,
+                    neighbor_y.rem_euclid(self.height as i32) as u32,
+                )),
+            };
+
+            if let Some((cx, cy)) = wrapped {
+                if let Some(&cell) = self.get_cell(cx, cy) {
+                    count += cell;
+                }
             }
         }
 
@@ -119,30 +387,117 @@ struct GameState {
     board_1: Board,
     board_2: Board,
     mouse_down: bool,
-    cycle: u32,                // Track the current cycle
-    last_update: Instant,      // Track the last update time
-    update_interval: Duration, // Set the update interval
+    cycle: u32,            // Track the current cycle
+    last_frame: Instant,   // Track when the previous frame was processed
+    accumulator: Duration, // Real time not yet converted into simulation ticks
+    dt: Duration,          // Fixed timestep -- the period of one generation
+    paused: bool,          // When true, the accumulator is not drained
+    // Reusable GPU-side buffer of per-cell draw instances. Built once here
+    // and repopulated each frame instead of allocating a `Mesh` per alive
+    // cell, so the whole board draws in a single batched call.
+    cell_instances: InstanceArray,
+    cell_size: i32, // Pixel size of one cell, from `Config`
+    density: f32,   // Fill ratio used by the randomize button
+    rule: Rule,     // Active birth/survival rule
+    // Index into RULE_PRESETS matching `rule`, for the toolbar label and
+    // as the Rule button's starting point when cycling. `RULE_PRESETS.len()`
+    // means `rule` doesn't match any preset (e.g. a custom `--rule`).
+    rule_preset_index: usize,
+    dragging_widget: Option<ToolbarWidget>, // Toolbar widget (if any) the current drag started on
 }
 
 impl GameState {
-    // Initialize a new game state with a randomized board
-    fn new() -> GameState {
+    // Initialize a new game state, sized and seeded from `config`
+    fn new(ctx: &mut Context, config: Config) -> GameState {
+        // A single solid-white 1x1 pixel, tinted per-instance via
+        // `DrawParam::color` and scaled up to a cell-sized square.
+        let cell_image = Image::from_color(ctx, 1, 1, Some(Color::WHITE));
+
         let mut game = GameState {
-            board_1: Board::new(GRID_WIDTH, GRID_HEIGHT),
-            board_2: Board::new(GRID_WIDTH, GRID_HEIGHT),
+            board_1: Board::new(config.grid_width, config.grid_height, config.boundary),
+            board_2: Board::new(config.grid_width, config.grid_height, config.boundary),
             mouse_down: false,
             cycle: 0,
-            last_update: Instant::now(),
+            last_frame: Instant::now(),
+            accumulator: Duration::ZERO,
             // I think this should be 60hz tick rate, but I'm not sure.
-            update_interval: Duration::from_secs_f32(1.0 / 60.0),
+            dt: Duration::from_secs_f32(1.0 / 60.0),
+            paused: false,
+            cell_instances: InstanceArray::new(ctx, cell_image),
+            cell_size: config.cell_size,
+            density: config.density,
+            rule: config.rule,
+            // Find which preset (if any) the configured rule matches, so
+            // the toolbar starts in agreement with the active rule instead
+            // of always claiming "Conway".
+            rule_preset_index: RULE_PRESETS
+                .iter()
+                .position(|(_, rulestring)| Rule::parse(rulestring) == Some(config.rule))
+                .unwrap_or(RULE_PRESETS.len()),
+            dragging_widget: None,
         };
-//        game.randomize();
+        game.board_1.randomize(game.density, config.seed);
         game
     }
 
-    // Randomize the board
+    // Randomize the board using the toolbar's current density setting. A
+    // fresh time-based seed is used so repeated clicks produce different
+    // layouts -- reproducing a specific layout is what `--seed` is for.
     fn randomize(&mut self) {
-        self.board_1.randomize();
+        self.board_1.randomize(self.density, time_based_seed());
+    }
+
+    // Width of the board in screen pixels, used to size toolbar chrome
+    // that should span the full window width.
+    fn screen_width(&self) -> f32 {
+        self.board_1.width as f32 * self.cell_size as f32
+    }
+
+    // Handle a press on the toolbar strip. `x` is in toolbar-local
+    // coordinates (same as screen x, since the toolbar spans the top).
+    fn handle_toolbar_click(&mut self, x: f32, widget: Option<ToolbarWidget>) {
+        match widget {
+            Some(ToolbarWidget::PauseButton) => self.paused = !self.paused,
+            Some(ToolbarWidget::RandomizeButton) => self.randomize(),
+            Some(ToolbarWidget::ClearButton) => self.board_1.cells.fill(0),
+            Some(ToolbarWidget::RuleButton) => {
+                // From a custom (non-preset) rule, the first click lands
+                // on the first preset rather than wrapping past it.
+                self.rule_preset_index = if self.rule_preset_index >= RULE_PRESETS.len() {
+                    0
+                } else {
+                    (self.rule_preset_index + 1) % RULE_PRESETS.len()
+                };
+                self.rule = Rule::parse(RULE_PRESETS[self.rule_preset_index].1)
+                    .expect("toolbar rule presets are valid rulestrings");
+            }
+            Some(ToolbarWidget::DensitySlider) => self.set_density_from_x(x),
+            None => {}
+        }
+    }
+
+    // Set `density` from where along the density slider's slot `x` falls.
+    fn set_density_from_x(&mut self, x: f32) {
+        let (_, left, right) = ToolbarWidget::layout(self.screen_width())
+            .into_iter()
+            .find(|&(widget, _, _)| widget == ToolbarWidget::DensitySlider)
+            .expect("DensitySlider is always present in the toolbar layout");
+
+        self.density = ((x - left) / (right - left)).clamp(0.0, 1.0);
+    }
+
+    // Advance the simulation by exactly one generation, swapping the
+    // double-buffered boards and bumping the cycle counter. This is the
+    // unit of work the fixed-timestep accumulator drains in `update`, and
+    // it's also what a single-step keypress triggers while paused.
+    fn run_one_tick(&mut self) {
+        let (current_board, future_board) = match self.cycle % 2 {
+            0 => (&mut self.board_1, &mut self.board_2),
+            _ => (&mut self.board_2, &mut self.board_1),
+        };
+        current_board.update(future_board, &self.rule);
+
+        self.cycle += 1;
     }
 
     // Handle mouse events to "spawn" cells
@@ -152,8 +507,8 @@ impl GameState {
         //not grid coordinates.
         //Without this scaling, I was only able to spawn cells neaer the top left corner
 
-        let grid_x = (x / GRID_CELL_SIZE as f32) as u32;
-        let grid_y = (y / GRID_CELL_SIZE as f32) as u32;
+        let grid_x = (x / self.cell_size as f32) as u32;
+        let grid_y = (y / self.cell_size as f32) as u32;
 
         if let Some(cell) = match self.cycle % 2 {
             0 => self.board_1.get_cell_mut(grid_x, grid_y),
@@ -162,28 +517,81 @@ impl GameState {
             *cell = 1;
         }
     }
+
+    // Draw the toolbar strip: a background bar plus one outlined button
+    // (or slider) per `ToolbarWidget`, each labeled with its current
+    // value. Drawn fresh every frame, same as the rest of the UI.
+    fn draw_toolbar(&self, ctx: &mut Context, canvas: &mut graphics::Canvas) -> GameResult {
+        let background = Mesh::new_rectangle(
+            ctx,
+            DrawMode::fill(),
+            Rect::new(0.0, 0.0, self.screen_width(), TOOLBAR_HEIGHT),
+            Color::from_rgb(30, 30, 30),
+        )?;
+        canvas.draw(&background, DrawParam::default());
+
+        for (widget, left, right) in ToolbarWidget::layout(self.screen_width()) {
+            let outline = Mesh::new_rectangle(
+                ctx,
+                DrawMode::stroke(1.0),
+                Rect::new(left + 2.0, 2.0, (right - left) - 4.0, TOOLBAR_HEIGHT - 4.0),
+                Color::from_rgb(150, 150, 150),
+            )?;
+            canvas.draw(&outline, DrawParam::default());
+
+            let label = match widget {
+                ToolbarWidget::PauseButton => {
+                    if self.paused {
+                        "Play".to_string()
+                    } else {
+                        "Pause".to_string()
+                    }
+                }
+                ToolbarWidget::RandomizeButton => "Randomize".to_string(),
+                ToolbarWidget::ClearButton => "Clear".to_string(),
+                ToolbarWidget::RuleButton => RULE_PRESETS
+                    .get(self.rule_preset_index)
+                    .map(|(name, _)| name.to_string())
+                    .unwrap_or_else(|| "Custom".to_string()),
+                ToolbarWidget::DensitySlider => format!("Density {:.0}%", self.density * 100.0),
+            };
+            canvas.draw(
+                &Text::new(label),
+                DrawParam::default()
+                    .dest([left + 8.0, 8.0])
+                    .color(Color::WHITE),
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl EventHandler for GameState {
     // Update the game state
+    //
+    // Uses a fixed-timestep accumulator: real frame time is banked into
+    // `accumulator` and the board is advanced one generation at a time for
+    // every full `dt` banked, so the number of generations simulated is
+    // independent of the render frame rate. While paused, frame time isn't
+    // banked at all, so the time spent paused never shows up as a burst of
+    // catch-up ticks on resume -- play just continues at normal speed.
     fn update(&mut self, _ctx: &mut Context) -> GameResult {
-        // Check if it's time to update the board
-        if self.last_update.elapsed() >= self.update_interval {
-            self.last_update = Instant::now(); // Reset the timer
+        let now = Instant::now();
+        let frame_time = now - self.last_frame;
+        self.last_frame = now;
 
-            let (current_board, future_board) = match self.cycle % 2 {
-                0 => (&mut self.board_1, &mut self.board_2),
-                _ => (&mut self.board_2, &mut self.board_1),
-            };
-            current_board.update(future_board);
+        if self.paused {
+            return Ok(());
+        }
 
-            self.cycle += 1;
+        self.accumulator += frame_time;
 
-            println!(
-                "Cycle {}: Update took {:?}",
-                self.cycle,
-                self.last_update.elapsed()
-            );
+        let mut ticks_this_update = 0;
+        while self.accumulator >= self.dt && ticks_this_update < MAX_TICKS_PER_UPDATE {
+            self.run_one_tick();
+            self.accumulator -= self.dt;
+            ticks_this_update += 1;
         }
 
         Ok(())
@@ -197,21 +605,25 @@ impl EventHandler for GameState {
 
         let mut canvas = graphics::Canvas::from_frame(ctx, Color::BLACK);
 
+        self.draw_toolbar(ctx, &mut canvas)?;
+
+        self.cell_instances.clear();
         for i in 0..self.board_1.cells.len() {
             let cell = self.board_1.cells[i];
             if cell == 1 {
-                let (x, y) = get_coordinates(i as i32);
-                let rect = Rect::new(
-                    (x * GRID_CELL_SIZE) as f32,
-                    (y * GRID_CELL_SIZE) as f32,
-                    GRID_CELL_SIZE as f32,
-                    GRID_CELL_SIZE as f32,
+                let (x, y) = get_coordinates(i as i32, self.board_1.width);
+                self.cell_instances.push(
+                    DrawParam::new()
+                        .dest([
+                            (x * self.cell_size) as f32,
+                            (y * self.cell_size) as f32 + TOOLBAR_HEIGHT,
+                        ])
+                        .scale([self.cell_size as f32, self.cell_size as f32])
+                        .color(Color::MAGENTA),
                 );
-
-                let square = Mesh::new_rectangle(ctx, DrawMode::fill(), rect, Color::MAGENTA)?;
-                canvas.draw(&square, DrawParam::default());
             }
         }
+        canvas.draw(&self.cell_instances, DrawParam::default());
 
         canvas.finish(ctx)?;
 
@@ -226,7 +638,10 @@ impl EventHandler for GameState {
         Ok(())
     }
 
-    // Handle mouse button down event
+    // Handle mouse button down event. Clicks landing in the toolbar strip
+    // manipulate whichever widget they land on; clicks below it spawn
+    // (set alive) grid cells as before. Which region the click started in is
+    // remembered so a drag that wanders between them doesn't do both.
     fn mouse_button_down_event(
         &mut self,
         _ctx: &mut Context,
@@ -236,7 +651,15 @@ impl EventHandler for GameState {
     ) -> GameResult {
         if button == mouse::MouseButton::Left {
             self.mouse_down = true;
-            self.handle_mouse(x, y);
+
+            if y < TOOLBAR_HEIGHT {
+                let widget = ToolbarWidget::at(x, self.screen_width());
+                self.dragging_widget = widget;
+                self.handle_toolbar_click(x, widget);
+            } else {
+                self.dragging_widget = None;
+                self.handle_mouse(x, y - TOOLBAR_HEIGHT);
+            }
         }
         Ok(())
     }
@@ -251,6 +674,7 @@ impl EventHandler for GameState {
     ) -> GameResult {
         if button == mouse::MouseButton::Left {
             self.mouse_down = false;
+            self.dragging_widget = None;
         }
         Ok(())
     }
@@ -264,20 +688,77 @@ impl EventHandler for GameState {
         _: f32,
         _: f32,
     ) -> GameResult {
-        if self.mouse_down {
-            self.handle_mouse(x, y);
+        if !self.mouse_down {
+            return Ok(());
+        }
+
+        match self.dragging_widget {
+            Some(ToolbarWidget::DensitySlider) => self.set_density_from_x(x),
+            Some(_) => {} // Other widgets only react to the initial click.
+            None => {
+                if y >= TOOLBAR_HEIGHT {
+                    self.handle_mouse(x, y - TOOLBAR_HEIGHT);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Handle key presses for playback control: Space toggles pause, Right
+    // single-steps one generation while paused, Up/Down halve or double
+    // the tick period to speed up or slow down the simulation, and T
+    // toggles between bounded and toroidal edges.
+    fn key_down_event(
+        &mut self,
+        _ctx: &mut Context,
+        input: KeyInput,
+        repeated: bool,
+    ) -> GameResult {
+        if repeated {
+            return Ok(());
+        }
+
+        match input.keycode {
+            Some(KeyCode::Space) => self.paused = !self.paused,
+            Some(KeyCode::Right) if self.paused => {
+                self.run_one_tick();
+            }
+            Some(KeyCode::Up) => {
+                self.dt = (self.dt / 2).max(MIN_DT);
+            }
+            Some(KeyCode::Down) => {
+                self.dt = (self.dt * 2).min(MAX_DT);
+            }
+            Some(KeyCode::T) => {
+                let next = match self.board_1.boundary {
+                    BoundaryMode::Bounded => BoundaryMode::Toroidal,
+                    BoundaryMode::Toroidal => BoundaryMode::Bounded,
+                };
+                self.board_1.boundary = next;
+                self.board_2.boundary = next;
+            }
+            _ => {}
         }
+
         Ok(())
     }
 }
 
 // Main function to start the game
 fn main() -> GameResult {
-    let (ctx, event_loop) = ContextBuilder::new("game_of_life", "JASC")
+    let config = Config::from_args(std::env::args().skip(1));
+
+    let screen_size = (
+        config.grid_width as f32 * config.cell_size as f32,
+        config.grid_height as f32 * config.cell_size as f32 + TOOLBAR_HEIGHT,
+    );
+
+    let (mut ctx, event_loop) = ContextBuilder::new("game_of_life", "JASC")
         .window_setup(ggez::conf::WindowSetup::default().title("Game of Life"))
-        .window_mode(ggez::conf::WindowMode::default().dimensions(SCREEN_SIZE.0, SCREEN_SIZE.1))
+        .window_mode(ggez::conf::WindowMode::default().dimensions(screen_size.0, screen_size.1))
         .build()?;
 
-    let state = GameState::new();
+    let state = GameState::new(&mut ctx, config);
     event::run(ctx, event_loop, state)
 }